@@ -4,8 +4,13 @@
 
 use iter::*;
 use iter::internal::*;
+use join;
 use std::borrow::{Borrow, BorrowMut};
 use std::cmp;
+use std::cmp::Ordering;
+
+use self::mergesort::par_mergesort;
+use self::quicksort::par_quicksort;
 
 /// Parallel extensions for slices.
 pub trait ParallelSlice<T: Sync>: Borrow<[T]> {
@@ -20,6 +25,31 @@ pub trait ParallelSlice<T: Sync>: Borrow<[T]> {
         }
     }
 
+    /// Returns a parallel iterator over subslices separated by elements that
+    /// match the separator, in reverse order.
+    fn par_rsplit<P>(&self, separator: P) -> RSplit<T, P>
+        where P: Fn(&T) -> bool + Sync
+    {
+        RSplit {
+            slice: self.borrow(),
+            separator: separator,
+        }
+    }
+
+    /// Returns a parallel iterator over the slice producing non-overlapping
+    /// runs of elements using the predicate to separate them.
+    ///
+    /// The predicate is called for adjacent pairs of elements until it
+    /// returns `false`, starting a new run from there.
+    fn par_chunk_by<P>(&self, pred: P) -> ChunkBy<T, P>
+        where P: Fn(&T, &T) -> bool + Sync
+    {
+        ChunkBy {
+            slice: self.borrow(),
+            pred: pred,
+        }
+    }
+
     /// Returns a parallel iterator over all contiguous windows of
     /// length `size`. The windows overlap.
     fn par_windows(&self, window_size: usize) -> Windows<T> {
@@ -37,6 +67,33 @@ pub trait ParallelSlice<T: Sync>: Borrow<[T]> {
             slice: self.borrow(),
         }
     }
+
+    /// Returns a parallel iterator over at most `size` elements of
+    /// `self` at a time, starting at the end. The chunks do not
+    /// overlap. If `chunk_size` does not divide the length of the
+    /// slice, then the last chunk produced will be the remainder, and
+    /// will be shorter than `chunk_size`.
+    fn par_rchunks(&self, chunk_size: usize) -> RChunks<T> {
+        RChunks {
+            chunk_size: chunk_size,
+            slice: self.borrow(),
+        }
+    }
+
+    /// Returns a parallel iterator over `chunk_size` elements of
+    /// `self` at a time. The chunks do not overlap, and any remainder
+    /// is ignored and can be retrieved with `remainder()`.
+    fn par_chunks_exact(&self, chunk_size: usize) -> ChunksExact<T> {
+        let slice = self.borrow();
+        let rem_len = slice.len() % chunk_size;
+        let len = slice.len() - rem_len;
+        let (slice, rem) = slice.split_at(len);
+        ChunksExact {
+            chunk_size: chunk_size,
+            slice: slice,
+            rem: rem,
+        }
+    }
 }
 
 impl<T: Sync, V: ?Sized + Borrow<[T]>> ParallelSlice<T> for V {}
@@ -52,11 +109,548 @@ pub trait ParallelSliceMut<T: Send>: BorrowMut<[T]> {
             slice: self.borrow_mut(),
         }
     }
+
+    /// Returns a parallel iterator over at most `size` elements of
+    /// `self` at a time, starting at the end. The chunks are mutable
+    /// and do not overlap. If `chunk_size` does not divide the length
+    /// of the slice, then the last chunk produced will be the
+    /// remainder, and will be shorter than `chunk_size`.
+    fn par_rchunks_mut(&mut self, chunk_size: usize) -> RChunksMut<T> {
+        RChunksMut {
+            chunk_size: chunk_size,
+            slice: self.borrow_mut(),
+        }
+    }
+
+    /// Returns a parallel iterator over `chunk_size` elements of
+    /// `self` at a time. The chunks are mutable, do not overlap, and
+    /// any remainder is ignored and can be retrieved with
+    /// `into_remainder()`.
+    fn par_chunks_exact_mut(&mut self, chunk_size: usize) -> ChunksExactMut<T> {
+        let slice = self.borrow_mut();
+        let rem_len = slice.len() % chunk_size;
+        let len = slice.len() - rem_len;
+        let (slice, rem) = slice.split_at_mut(len);
+        ChunksExactMut {
+            chunk_size: chunk_size,
+            slice: slice,
+            rem: rem,
+        }
+    }
+
+    /// Returns a parallel iterator over mutable subslices separated by
+    /// elements that match the separator.
+    fn par_split_mut<P>(&mut self, separator: P) -> SplitMut<T, P>
+        where P: Fn(&T) -> bool + Sync
+    {
+        SplitMut {
+            slice: self.borrow_mut(),
+            separator: separator,
+        }
+    }
+
+    /// Returns a parallel iterator over mutable subslices separated by
+    /// elements that match the separator, in reverse order.
+    fn par_rsplit_mut<P>(&mut self, separator: P) -> RSplitMut<T, P>
+        where P: Fn(&T) -> bool + Sync
+    {
+        RSplitMut {
+            slice: self.borrow_mut(),
+            separator: separator,
+        }
+    }
+
+    /// Returns a parallel iterator over the slice producing non-overlapping
+    /// mutable runs of elements using the predicate to separate them.
+    ///
+    /// The predicate is called for adjacent pairs of elements until it
+    /// returns `false`, starting a new run from there.
+    fn par_chunk_by_mut<P>(&mut self, pred: P) -> ChunkByMut<T, P>
+        where P: Fn(&T, &T) -> bool + Sync
+    {
+        ChunkByMut {
+            slice: self.borrow_mut(),
+            pred: pred,
+        }
+    }
+
+    /// Sorts the slice in parallel.
+    ///
+    /// This sort is stable (i.e. does not reorder equal elements) and `O(n
+    /// log n)` worst-case. It is implemented as a parallel merge sort: the
+    /// slice is recursively halved, the two halves are sorted in parallel,
+    /// and then merged back together.
+    fn par_sort(&mut self)
+        where T: Ord
+    {
+        par_mergesort(self.borrow_mut(), &|a, b| a.lt(b));
+    }
+
+    /// Sorts the slice in parallel with a comparator function.
+    ///
+    /// This sort is stable (i.e. does not reorder equal elements) and `O(n
+    /// log n)` worst-case. See [`par_sort`](#method.par_sort) for details on
+    /// the underlying algorithm.
+    fn par_sort_by<F>(&mut self, compare: F)
+        where F: Fn(&T, &T) -> Ordering + Sync
+    {
+        par_mergesort(self.borrow_mut(), &|a, b| compare(a, b) == Ordering::Less);
+    }
+
+    /// Sorts the slice in parallel with a key extraction function.
+    ///
+    /// This sort is stable (i.e. does not reorder equal elements) and `O(n
+    /// log n)` worst-case. See [`par_sort`](#method.par_sort) for details on
+    /// the underlying algorithm.
+    fn par_sort_by_key<K, F>(&mut self, f: F)
+        where K: Ord,
+              F: Fn(&T) -> K + Sync
+    {
+        par_mergesort(self.borrow_mut(), &|a, b| f(a).lt(&f(b)));
+    }
+
+    /// Sorts the slice in parallel, but may not preserve the order of equal
+    /// elements.
+    ///
+    /// This sort is unstable and `O(n log n)` worst-case. It is implemented
+    /// as a parallel quicksort: the slice is partitioned around a
+    /// median-of-three pivot and the two halves are then sorted in
+    /// parallel, falling back to the sequential `sort_unstable_by` once the
+    /// recursion depth suggests an unbalanced, pathological partition.
+    fn par_sort_unstable(&mut self)
+        where T: Ord
+    {
+        par_quicksort(self.borrow_mut(), &|a, b| a.lt(b));
+    }
 }
 
 impl<T: Send, V: ?Sized + BorrowMut<[T]>> ParallelSliceMut<T> for V {}
 
 
+/// Parallel merge sort, used to implement `par_sort`, `par_sort_by`, and
+/// `par_sort_by_key`.
+mod mergesort {
+    use join;
+    use std::mem;
+    use std::ptr;
+
+    /// Up to and including this length, `par_mergesort` falls back to the sequential
+    /// `slice::sort_by`, since the overhead of splitting and merging in
+    /// parallel no longer pays for itself.
+    const SEQUENTIAL_FALLBACK: usize = 2048;
+
+    /// A raw pointer into the scratch buffer, wrapped so it can cross the
+    /// `join` boundary. This is safe because the two halves of a `join`
+    /// always write to disjoint regions of the buffer.
+    struct MergeTarget<T>(*mut T);
+
+    // `#[derive(Clone, Copy)]` would add a spurious `T: Copy` bound; a raw
+    // pointer is `Copy` regardless of what it points to, so implement both
+    // by hand.
+    impl<T> Clone for MergeTarget<T> {
+        fn clone(&self) -> Self {
+            MergeTarget(self.0)
+        }
+    }
+
+    impl<T> Copy for MergeTarget<T> {}
+
+    unsafe impl<T: Send> Send for MergeTarget<T> {}
+
+    impl<T> MergeTarget<T> {
+        unsafe fn offset(self, count: isize) -> Self {
+            MergeTarget(self.0.offset(count))
+        }
+    }
+
+    /// Sorts `v` in parallel, using `is_less` to compare elements. The sort
+    /// is stable: equal elements are not reordered.
+    pub fn par_mergesort<T, F>(v: &mut [T], is_less: &F)
+        where T: Send,
+              F: Fn(&T, &T) -> bool + Sync
+    {
+        let len = v.len();
+        if len <= SEQUENTIAL_FALLBACK {
+            v.sort_by(|a, b| if is_less(a, b) {
+                          ::std::cmp::Ordering::Less
+                      } else if is_less(b, a) {
+                          ::std::cmp::Ordering::Greater
+                      } else {
+                          ::std::cmp::Ordering::Equal
+                      });
+            return;
+        }
+
+        let mid = len / 2;
+        let (left, right) = v.split_at_mut(mid);
+        join(|| par_mergesort(left, is_less), || par_mergesort(right, is_less));
+
+        // Merge the two sorted halves into a scratch buffer, then copy the
+        // result back into `v`.
+        let mut buf: Vec<T> = Vec::with_capacity(len);
+        unsafe {
+            par_merge(left, right, MergeTarget(buf.as_mut_ptr()), is_less);
+            ptr::copy_nonoverlapping(buf.as_ptr(), v.as_mut_ptr(), len);
+
+            // The elements now live in `v`; forget about them here so they
+            // are not dropped a second time when `buf` goes out of scope.
+            buf.set_len(0);
+        }
+    }
+
+    /// Merges the sorted slices `left` and `right` into the uninitialized
+    /// memory at `target`, recursing in parallel via `join`.
+    ///
+    /// # Safety
+    ///
+    /// `target` must point to `left.len() + right.len()` elements of
+    /// uninitialized (or otherwise forgettable) memory.
+    unsafe fn par_merge<T, F>(left: &mut [T], right: &mut [T], target: MergeTarget<T>, is_less: &F)
+        where T: Send,
+              F: Fn(&T, &T) -> bool + Sync
+    {
+        if left.len() + right.len() <= SEQUENTIAL_FALLBACK {
+            merge_sequential(left, right, target.0, is_less);
+            return;
+        }
+
+        // Split the longer of the two halves in the middle, and binary
+        // search for where that pivot would land in the shorter half, so
+        // that neither of the two resulting merges depends on the other.
+        let (left, right, swapped) = if left.len() >= right.len() {
+            (left, right, false)
+        } else {
+            (right, left, true)
+        };
+
+        // The pivot is always drawn from `left`, but which original slice
+        // `left` refers to depends on `swapped`. To keep the merge stable,
+        // ties must still be broken in favor of whichever slice was
+        // originally on the left: when `swapped` is true, `left` is really
+        // the later slice, so elements of `right` (the earlier slice) equal
+        // to the pivot must land *before* it instead of after.
+        let left_mid = left.len() / 2;
+        let (left_lo, left_hi) = left.split_at_mut(left_mid);
+        let right_mid = if swapped {
+            partition_point_upper(right, &left_hi[0], is_less)
+        } else {
+            partition_point(right, &left_hi[0], is_less)
+        };
+        let (right_lo, right_hi) = right.split_at_mut(right_mid);
+
+        let target_mid = target.offset((left_mid + right_mid) as isize);
+
+        if swapped {
+            join(move || par_merge(right_lo, left_lo, target, is_less),
+                 move || par_merge(right_hi, left_hi, target_mid, is_less));
+        } else {
+            join(move || par_merge(left_lo, right_lo, target, is_less),
+                 move || par_merge(left_hi, right_hi, target_mid, is_less));
+        }
+    }
+
+    /// Returns the index of the first element of `s` that is not less than
+    /// `pivot`, found via binary search. Elements of `s` equal to `pivot`
+    /// are treated as not less than it, i.e. they land after the pivot.
+    fn partition_point<T, F>(s: &[T], pivot: &T, is_less: &F) -> usize
+        where F: Fn(&T, &T) -> bool
+    {
+        let mut lo = 0;
+        let mut hi = s.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if is_less(&s[mid], pivot) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+
+    /// Returns the index of the first element of `s` that is greater than
+    /// `pivot`, found via binary search. Elements of `s` equal to `pivot`
+    /// are treated as not greater than it, i.e. they land before the pivot.
+    ///
+    /// This is the mirror image of `partition_point`, needed when `pivot`
+    /// comes from a slice that is logically *later* than `s`: ties must
+    /// then land before the pivot to preserve the original relative order.
+    fn partition_point_upper<T, F>(s: &[T], pivot: &T, is_less: &F) -> usize
+        where F: Fn(&T, &T) -> bool
+    {
+        let mut lo = 0;
+        let mut hi = s.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if is_less(pivot, &s[mid]) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo
+    }
+
+    /// Merges the sorted slices `left` and `right` sequentially into the
+    /// uninitialized memory at `target`.
+    unsafe fn merge_sequential<T, F>(left: &[T], right: &[T], target: *mut T, is_less: &F)
+        where F: Fn(&T, &T) -> bool
+    {
+        let mut l = left.as_ptr();
+        let mut r = right.as_ptr();
+        let l_end = l.offset(left.len() as isize);
+        let r_end = r.offset(right.len() as isize);
+        let mut out = target;
+
+        while l < l_end && r < r_end {
+            if is_less(&*r, &*l) {
+                ptr::copy_nonoverlapping(r, out, 1);
+                r = r.offset(1);
+            } else {
+                ptr::copy_nonoverlapping(l, out, 1);
+                l = l.offset(1);
+            }
+            out = out.offset(1);
+        }
+
+        let (rest, rest_end) = if l < l_end { (l, l_end) } else { (r, r_end) };
+        let rest_len = (rest_end as usize - rest as usize) / mem::size_of::<T>();
+        ptr::copy_nonoverlapping(rest, out, rest_len);
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::par_mergesort;
+
+        // Regression test for a stability bug: when the `swapped` branch of
+        // `par_merge` drew its pivot from the logically later slice, ties
+        // were broken the wrong way and equal-keyed elements ended up
+        // reordered. With an all-equal-keys input, a stable sort must leave
+        // the slice untouched.
+        #[test]
+        fn par_mergesort_is_stable_with_many_equal_keys() {
+            // Large enough to recurse past `SEQUENTIAL_FALLBACK` several
+            // times and exercise the `swapped` branch of `par_merge`.
+            let mut v: Vec<(u8, usize)> = (0..5000).map(|i| (0, i)).collect();
+            let expected = v.clone();
+            par_mergesort(&mut v, &|a: &(u8, usize), b: &(u8, usize)| a.0 < b.0);
+            assert_eq!(v, expected);
+        }
+
+        #[test]
+        fn par_mergesort_matches_sequential_sort_on_random_input() {
+            let mut rng = 0x2545_f491_4f6c_dd1du64;
+            let mut next = move || {
+                rng ^= rng << 13;
+                rng ^= rng >> 7;
+                rng ^= rng << 17;
+                rng
+            };
+
+            for len in &[0usize, 1, 2, 31, 32, 2048, 2049, 10_000] {
+                let mut v: Vec<i64> = (0..*len).map(|_| (next() % 1000) as i64).collect();
+                let mut expected = v.clone();
+                expected.sort();
+                par_mergesort(&mut v, &|a: &i64, b: &i64| a < b);
+                assert_eq!(v, expected);
+            }
+        }
+
+        #[test]
+        fn par_mergesort_preserves_order_of_equal_keys_on_random_input() {
+            // Tag every element with its original index, sort only by the
+            // (highly-colliding) key, and check that ties keep their
+            // original relative order -- the definition of a stable sort.
+            let mut rng = 0x9e37_79b9_7f4a_7c15u64;
+            let mut next = move || {
+                rng ^= rng << 13;
+                rng ^= rng >> 7;
+                rng ^= rng << 17;
+                rng
+            };
+
+            for len in &[0usize, 1, 2, 4096, 10_000] {
+                let mut v: Vec<(u8, usize)> = (0..*len)
+                    .map(|i| ((next() % 8) as u8, i))
+                    .collect();
+                par_mergesort(&mut v, &|a: &(u8, usize), b: &(u8, usize)| a.0 < b.0);
+
+                let mut by_key: Vec<Vec<usize>> = vec![Vec::new(); 8];
+                for &(key, index) in &v {
+                    by_key[key as usize].push(index);
+                }
+                for run in &by_key {
+                    assert!(run.windows(2).all(|w| w[0] < w[1]));
+                }
+            }
+        }
+    }
+}
+
+/// Parallel quicksort, used to implement `par_sort_unstable`.
+mod quicksort {
+    use join;
+
+    /// Up to and including this length, `par_quicksort` falls back to the sequential
+    /// `slice::sort_unstable_by`.
+    const SEQUENTIAL_FALLBACK: usize = 2048;
+
+    /// Sorts `v` in parallel, using `is_less` to compare elements. The sort
+    /// is not stable: equal elements may be reordered.
+    pub fn par_quicksort<T, F>(v: &mut [T], is_less: &F)
+        where T: Send,
+              F: Fn(&T, &T) -> bool + Sync
+    {
+        let limit = recursion_limit(v.len());
+        par_quicksort_helper(v, is_less, limit);
+    }
+
+    /// Like `par_quicksort`, but gives up on partitioning (and falls back
+    /// to the sequential, guaranteed-`O(n log n)` `sort_unstable_by`) once
+    /// `limit` reaches zero. This bounds both the total work and the
+    /// recursion depth even if the pivot choice below turns out to be
+    /// adversarial, so a handful of unlucky partitions can never degrade
+    /// into the classical `O(n^2)` quicksort worst case.
+    fn par_quicksort_helper<T, F>(v: &mut [T], is_less: &F, limit: u32)
+        where T: Send,
+              F: Fn(&T, &T) -> bool + Sync
+    {
+        let len = v.len();
+        if len <= SEQUENTIAL_FALLBACK || limit == 0 {
+            v.sort_unstable_by(|a, b| if is_less(a, b) {
+                                   ::std::cmp::Ordering::Less
+                               } else if is_less(b, a) {
+                                   ::std::cmp::Ordering::Greater
+                               } else {
+                                   ::std::cmp::Ordering::Equal
+                               });
+            return;
+        }
+
+        let mid = partition(v, is_less);
+        let (left, right) = v.split_at_mut(mid);
+        let right = &mut right[1..]; // the pivot at `mid` is already in its final place
+        join(|| par_quicksort_helper(left, is_less, limit - 1),
+             || par_quicksort_helper(right, is_less, limit - 1));
+    }
+
+    /// Returns how many levels of partitioning `par_quicksort_helper` will
+    /// recurse through before giving up on quicksort entirely:
+    /// `2 * (floor(log2(len)) + 1)`, as in a classic introsort depth limit.
+    fn recursion_limit(len: usize) -> u32 {
+        let len = ::std::cmp::max(len, 1);
+        let bits = ::std::mem::size_of::<usize>() as u32 * 8;
+        2 * (bits - len.leading_zeros())
+    }
+
+    /// Partitions `v` around a pivot, moving it to its final sorted
+    /// position and returning that position's index.
+    fn partition<T, F>(v: &mut [T], is_less: &F) -> usize
+        where F: Fn(&T, &T) -> bool
+    {
+        let len = v.len();
+        select_pivot(v, is_less);
+
+        let mut store_index = 0;
+        for i in 1..len {
+            if is_less(&v[i], &v[0]) {
+                store_index += 1;
+                v.swap(store_index, i);
+            }
+        }
+
+        v.swap(0, store_index);
+        store_index
+    }
+
+    /// Moves the median of `v[0]`, `v[len / 2]` and `v[len - 1]` into
+    /// `v[0]`, so that `partition` uses it as the pivot. A fixed,
+    /// unrandomized single-element pivot (e.g. always `v[0]` or always
+    /// `v[len / 2]`) can be forced into the worst-case `(len - 1, 0)` split
+    /// at every level of recursion by an adversarially-ordered input;
+    /// median-of-three makes that substantially harder to construct, and
+    /// `recursion_limit` bounds the damage if it happens anyway.
+    fn select_pivot<T, F>(v: &mut [T], is_less: &F)
+        where F: Fn(&T, &T) -> bool
+    {
+        let len = v.len();
+        let mid = len / 2;
+        let last = len - 1;
+
+        if is_less(&v[mid], &v[0]) {
+            v.swap(0, mid);
+        }
+        if is_less(&v[last], &v[0]) {
+            v.swap(0, last);
+        }
+        if is_less(&v[last], &v[mid]) {
+            v.swap(mid, last);
+        }
+        v.swap(0, mid);
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{par_quicksort, recursion_limit};
+
+        #[test]
+        fn par_quicksort_matches_sequential_sort_on_random_input() {
+            let mut rng = 0x1234_5678_9abc_def0u64;
+            let mut next = move || {
+                rng ^= rng << 13;
+                rng ^= rng >> 7;
+                rng ^= rng << 17;
+                rng
+            };
+
+            for len in &[0usize, 1, 2, 31, 32, 2048, 2049, 10_000] {
+                let mut v: Vec<i64> = (0..*len).map(|_| (next() % 1000) as i64).collect();
+                let mut expected = v.clone();
+                expected.sort();
+                par_quicksort(&mut v, &|a: &i64, b: &i64| a < b);
+                assert_eq!(v, expected);
+            }
+        }
+
+        // A fixed single-element pivot (e.g. always `v[0]` or always
+        // `v[len / 2]`) can be forced into an `(n - 1, 0)` split at every
+        // level of recursion by an adversarially-ordered input, such as
+        // this one. With the median-of-three pivot and the recursion-depth
+        // fallback, this must still sort correctly (and, since the limit
+        // is `O(log n)`, without overflowing the stack).
+        #[test]
+        fn par_quicksort_handles_adversarial_input() {
+            let len = 20_000;
+            // Already sorted input defeats a "pivot is always the first or
+            // last element" strategy; a "pivot is always the middle
+            // element" strategy is defeated by a zig-zag ordering like this
+            // one instead.
+            let mut v: Vec<i64> = Vec::with_capacity(len);
+            let mut lo = 0i64;
+            let mut hi = len as i64 - 1;
+            while lo <= hi {
+                v.push(lo);
+                if lo != hi {
+                    v.push(hi);
+                }
+                lo += 1;
+                hi -= 1;
+            }
+
+            let expected: Vec<i64> = (0..len as i64).collect();
+            par_quicksort(&mut v, &|a: &i64, b: &i64| a < b);
+            assert_eq!(v, expected);
+        }
+
+        #[test]
+        fn recursion_limit_grows_logarithmically() {
+            assert!(recursion_limit(1) <= recursion_limit(1_000_000));
+            assert!(recursion_limit(1_000_000) < 100);
+        }
+    }
+}
+
+
 impl<'data, T: Sync + 'data> IntoParallelIterator for &'data [T] {
     type Item = &'data T;
     type Iter = Iter<'data, T>;
@@ -219,13 +813,13 @@ impl<'data, T: 'data + Sync> Producer for ChunksProducer<'data, T> {
 }
 
 
-/// Parallel iterator over immutable overlapping windows of a slice
-pub struct Windows<'data, T: 'data + Sync> {
-    window_size: usize,
+/// Parallel iterator over immutable non-overlapping chunks of a slice, starting at the end.
+pub struct RChunks<'data, T: 'data + Sync> {
+    chunk_size: usize,
     slice: &'data [T],
 }
 
-impl<'data, T: Sync + 'data> ParallelIterator for Windows<'data, T> {
+impl<'data, T: Sync + 'data> ParallelIterator for RChunks<'data, T> {
     type Item = &'data [T];
 
     fn drive_unindexed<C>(self, consumer: C) -> C::Result
@@ -239,7 +833,7 @@ impl<'data, T: Sync + 'data> ParallelIterator for Windows<'data, T> {
     }
 }
 
-impl<'data, T: Sync + 'data> IndexedParallelIterator for Windows<'data, T> {
+impl<'data, T: Sync + 'data> IndexedParallelIterator for RChunks<'data, T> {
     fn drive<C>(self, consumer: C) -> C::Result
         where C: Consumer<Self::Item>
     {
@@ -247,56 +841,68 @@ impl<'data, T: Sync + 'data> IndexedParallelIterator for Windows<'data, T> {
     }
 
     fn len(&mut self) -> usize {
-        assert!(self.window_size >= 1);
-        self.slice.len().saturating_sub(self.window_size - 1)
+        (self.slice.len() + (self.chunk_size - 1)) / self.chunk_size
     }
 
     fn with_producer<CB>(self, callback: CB) -> CB::Output
         where CB: ProducerCallback<Self::Item>
     {
-        callback.callback(WindowsProducer {
-                              window_size: self.window_size,
+        callback.callback(RChunksProducer {
+                              chunk_size: self.chunk_size,
                               slice: self.slice,
                           })
     }
 }
 
-struct WindowsProducer<'data, T: 'data + Sync> {
-    window_size: usize,
+struct RChunksProducer<'data, T: 'data + Sync> {
+    chunk_size: usize,
     slice: &'data [T],
 }
 
-impl<'data, T: 'data + Sync> Producer for WindowsProducer<'data, T> {
+impl<'data, T: 'data + Sync> Producer for RChunksProducer<'data, T> {
     type Item = &'data [T];
-    type IntoIter = ::std::slice::Windows<'data, T>;
+    type IntoIter = ::std::slice::RChunks<'data, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.slice.windows(self.window_size)
+        self.slice.rchunks(self.chunk_size)
     }
 
     fn split_at(self, index: usize) -> (Self, Self) {
-        let left_index = cmp::min(self.slice.len(), index + (self.window_size - 1));
-        let left = &self.slice[..left_index];
-        let right = &self.slice[index..];
-        (WindowsProducer {
-             window_size: self.window_size,
-             slice: left,
-         },
-         WindowsProducer {
-             window_size: self.window_size,
+        // Rather than dividing `index` chunks from the front, divide
+        // so that `index` chunks are split from the back, since the
+        // chunk boundaries (and the remainder) are counted from there.
+        let elem_index = self.slice.len() - index * self.chunk_size;
+        let (left, right) = self.slice.split_at(elem_index);
+        (RChunksProducer {
+             chunk_size: self.chunk_size,
              slice: right,
+         },
+         RChunksProducer {
+             chunk_size: self.chunk_size,
+             slice: left,
          })
     }
 }
 
 
-/// Parallel iterator over mutable items in a slice
-pub struct IterMut<'data, T: 'data + Send> {
-    slice: &'data mut [T],
+/// Parallel iterator over immutable non-overlapping chunks of a slice
+pub struct ChunksExact<'data, T: 'data + Sync> {
+    chunk_size: usize,
+    slice: &'data [T],
+    rem: &'data [T],
 }
 
-impl<'data, T: Send + 'data> ParallelIterator for IterMut<'data, T> {
-    type Item = &'data mut T;
+impl<'data, T: Sync + 'data> ChunksExact<'data, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `chunk_size - 1`
+    /// elements.
+    pub fn remainder(&self) -> &'data [T] {
+        self.rem
+    }
+}
+
+impl<'data, T: Sync + 'data> ParallelIterator for ChunksExact<'data, T> {
+    type Item = &'data [T];
 
     fn drive_unindexed<C>(self, consumer: C) -> C::Result
         where C: UnindexedConsumer<Self::Item>
@@ -309,7 +915,7 @@ impl<'data, T: Send + 'data> ParallelIterator for IterMut<'data, T> {
     }
 }
 
-impl<'data, T: Send + 'data> IndexedParallelIterator for IterMut<'data, T> {
+impl<'data, T: Sync + 'data> IndexedParallelIterator for ChunksExact<'data, T> {
     fn drive<C>(self, consumer: C) -> C::Result
         where C: Consumer<Self::Item>
     {
@@ -317,43 +923,55 @@ impl<'data, T: Send + 'data> IndexedParallelIterator for IterMut<'data, T> {
     }
 
     fn len(&mut self) -> usize {
-        self.slice.len()
+        self.slice.len() / self.chunk_size
     }
 
     fn with_producer<CB>(self, callback: CB) -> CB::Output
         where CB: ProducerCallback<Self::Item>
     {
-        callback.callback(IterMutProducer { slice: self.slice })
+        callback.callback(ChunksExactProducer {
+                              chunk_size: self.chunk_size,
+                              slice: self.slice,
+                          })
     }
 }
 
-struct IterMutProducer<'data, T: 'data + Send> {
-    slice: &'data mut [T],
+struct ChunksExactProducer<'data, T: 'data + Sync> {
+    chunk_size: usize,
+    slice: &'data [T],
 }
 
-impl<'data, T: 'data + Send> Producer for IterMutProducer<'data, T> {
-    type Item = &'data mut T;
-    type IntoIter = ::std::slice::IterMut<'data, T>;
+impl<'data, T: 'data + Sync> Producer for ChunksExactProducer<'data, T> {
+    type Item = &'data [T];
+    type IntoIter = ::std::slice::ChunksExact<'data, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.slice.into_iter()
+        self.slice.chunks_exact(self.chunk_size)
     }
 
     fn split_at(self, index: usize) -> (Self, Self) {
-        let (left, right) = self.slice.split_at_mut(index);
-        (IterMutProducer { slice: left }, IterMutProducer { slice: right })
+        let elem_index = index * self.chunk_size;
+        let (left, right) = self.slice.split_at(elem_index);
+        (ChunksExactProducer {
+             chunk_size: self.chunk_size,
+             slice: left,
+         },
+         ChunksExactProducer {
+             chunk_size: self.chunk_size,
+             slice: right,
+         })
     }
 }
 
 
-/// Parallel iterator over mutable non-overlapping chunks of a slice
-pub struct ChunksMut<'data, T: 'data + Send> {
-    chunk_size: usize,
-    slice: &'data mut [T],
+/// Parallel iterator over immutable overlapping windows of a slice
+pub struct Windows<'data, T: 'data + Sync> {
+    window_size: usize,
+    slice: &'data [T],
 }
 
-impl<'data, T: Send + 'data> ParallelIterator for ChunksMut<'data, T> {
-    type Item = &'data mut [T];
+impl<'data, T: Sync + 'data> ParallelIterator for Windows<'data, T> {
+    type Item = &'data [T];
 
     fn drive_unindexed<C>(self, consumer: C) -> C::Result
         where C: UnindexedConsumer<Self::Item>
@@ -366,7 +984,7 @@ impl<'data, T: Send + 'data> ParallelIterator for ChunksMut<'data, T> {
     }
 }
 
-impl<'data, T: Send + 'data> IndexedParallelIterator for ChunksMut<'data, T> {
+impl<'data, T: Sync + 'data> IndexedParallelIterator for Windows<'data, T> {
     fn drive<C>(self, consumer: C) -> C::Result
         where C: Consumer<Self::Item>
     {
@@ -374,15 +992,142 @@ impl<'data, T: Send + 'data> IndexedParallelIterator for ChunksMut<'data, T> {
     }
 
     fn len(&mut self) -> usize {
-        (self.slice.len() + (self.chunk_size - 1)) / self.chunk_size
+        assert!(self.window_size >= 1);
+        self.slice.len().saturating_sub(self.window_size - 1)
     }
 
     fn with_producer<CB>(self, callback: CB) -> CB::Output
         where CB: ProducerCallback<Self::Item>
     {
-        callback.callback(ChunksMutProducer {
-                              chunk_size: self.chunk_size,
-                              slice: self.slice,
+        callback.callback(WindowsProducer {
+                              window_size: self.window_size,
+                              slice: self.slice,
+                          })
+    }
+}
+
+struct WindowsProducer<'data, T: 'data + Sync> {
+    window_size: usize,
+    slice: &'data [T],
+}
+
+impl<'data, T: 'data + Sync> Producer for WindowsProducer<'data, T> {
+    type Item = &'data [T];
+    type IntoIter = ::std::slice::Windows<'data, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.windows(self.window_size)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let left_index = cmp::min(self.slice.len(), index + (self.window_size - 1));
+        let left = &self.slice[..left_index];
+        let right = &self.slice[index..];
+        (WindowsProducer {
+             window_size: self.window_size,
+             slice: left,
+         },
+         WindowsProducer {
+             window_size: self.window_size,
+             slice: right,
+         })
+    }
+}
+
+
+/// Parallel iterator over mutable items in a slice
+pub struct IterMut<'data, T: 'data + Send> {
+    slice: &'data mut [T],
+}
+
+impl<'data, T: Send + 'data> ParallelIterator for IterMut<'data, T> {
+    type Item = &'data mut T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&mut self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'data, T: Send + 'data> IndexedParallelIterator for IterMut<'data, T> {
+    fn drive<C>(self, consumer: C) -> C::Result
+        where C: Consumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&mut self) -> usize {
+        self.slice.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+        where CB: ProducerCallback<Self::Item>
+    {
+        callback.callback(IterMutProducer { slice: self.slice })
+    }
+}
+
+struct IterMutProducer<'data, T: 'data + Send> {
+    slice: &'data mut [T],
+}
+
+impl<'data, T: 'data + Send> Producer for IterMutProducer<'data, T> {
+    type Item = &'data mut T;
+    type IntoIter = ::std::slice::IterMut<'data, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.into_iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.slice.split_at_mut(index);
+        (IterMutProducer { slice: left }, IterMutProducer { slice: right })
+    }
+}
+
+
+/// Parallel iterator over mutable non-overlapping chunks of a slice
+pub struct ChunksMut<'data, T: 'data + Send> {
+    chunk_size: usize,
+    slice: &'data mut [T],
+}
+
+impl<'data, T: Send + 'data> ParallelIterator for ChunksMut<'data, T> {
+    type Item = &'data mut [T];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&mut self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'data, T: Send + 'data> IndexedParallelIterator for ChunksMut<'data, T> {
+    fn drive<C>(self, consumer: C) -> C::Result
+        where C: Consumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&mut self) -> usize {
+        (self.slice.len() + (self.chunk_size - 1)) / self.chunk_size
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+        where CB: ProducerCallback<Self::Item>
+    {
+        callback.callback(ChunksMutProducer {
+                              chunk_size: self.chunk_size,
+                              slice: self.slice,
                           })
     }
 }
@@ -415,6 +1160,159 @@ impl<'data, T: 'data + Send> Producer for ChunksMutProducer<'data, T> {
 }
 
 
+/// Parallel iterator over mutable non-overlapping chunks of a slice, starting at the end.
+pub struct RChunksMut<'data, T: 'data + Send> {
+    chunk_size: usize,
+    slice: &'data mut [T],
+}
+
+impl<'data, T: Send + 'data> ParallelIterator for RChunksMut<'data, T> {
+    type Item = &'data mut [T];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&mut self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'data, T: Send + 'data> IndexedParallelIterator for RChunksMut<'data, T> {
+    fn drive<C>(self, consumer: C) -> C::Result
+        where C: Consumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&mut self) -> usize {
+        (self.slice.len() + (self.chunk_size - 1)) / self.chunk_size
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+        where CB: ProducerCallback<Self::Item>
+    {
+        callback.callback(RChunksMutProducer {
+                              chunk_size: self.chunk_size,
+                              slice: self.slice,
+                          })
+    }
+}
+
+struct RChunksMutProducer<'data, T: 'data + Send> {
+    chunk_size: usize,
+    slice: &'data mut [T],
+}
+
+impl<'data, T: 'data + Send> Producer for RChunksMutProducer<'data, T> {
+    type Item = &'data mut [T];
+    type IntoIter = ::std::slice::RChunksMut<'data, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.rchunks_mut(self.chunk_size)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        // As with `RChunksProducer`, chunk boundaries (and the remainder)
+        // are counted from the end, so `index` chunks come off the back.
+        let elem_index = self.slice.len() - index * self.chunk_size;
+        let (left, right) = self.slice.split_at_mut(elem_index);
+        (RChunksMutProducer {
+             chunk_size: self.chunk_size,
+             slice: right,
+         },
+         RChunksMutProducer {
+             chunk_size: self.chunk_size,
+             slice: left,
+         })
+    }
+}
+
+
+/// Parallel iterator over mutable non-overlapping chunks of a slice
+pub struct ChunksExactMut<'data, T: 'data + Send> {
+    chunk_size: usize,
+    slice: &'data mut [T],
+    rem: &'data mut [T],
+}
+
+impl<'data, T: Send + 'data> ChunksExactMut<'data, T> {
+    /// Returns the remainder of the original slice that is not going to be
+    /// returned by the iterator. The returned slice has at most `chunk_size - 1`
+    /// elements.
+    ///
+    /// Consumes `self`, as the mutable reference to the remainder can only
+    /// be returned once.
+    pub fn into_remainder(self) -> &'data mut [T] {
+        self.rem
+    }
+}
+
+impl<'data, T: Send + 'data> ParallelIterator for ChunksExactMut<'data, T> {
+    type Item = &'data mut [T];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&mut self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<'data, T: Send + 'data> IndexedParallelIterator for ChunksExactMut<'data, T> {
+    fn drive<C>(self, consumer: C) -> C::Result
+        where C: Consumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&mut self) -> usize {
+        self.slice.len() / self.chunk_size
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+        where CB: ProducerCallback<Self::Item>
+    {
+        callback.callback(ChunksExactMutProducer {
+                              chunk_size: self.chunk_size,
+                              slice: self.slice,
+                          })
+    }
+}
+
+struct ChunksExactMutProducer<'data, T: 'data + Send> {
+    chunk_size: usize,
+    slice: &'data mut [T],
+}
+
+impl<'data, T: 'data + Send> Producer for ChunksExactMutProducer<'data, T> {
+    type Item = &'data mut [T];
+    type IntoIter = ::std::slice::ChunksExactMut<'data, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.chunks_exact_mut(self.chunk_size)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let elem_index = index * self.chunk_size;
+        let (left, right) = self.slice.split_at_mut(elem_index);
+        (ChunksExactMutProducer {
+             chunk_size: self.chunk_size,
+             slice: left,
+         },
+         ChunksExactMutProducer {
+             chunk_size: self.chunk_size,
+             slice: right,
+         })
+    }
+}
+
+
 /// Parallel iterator over slices separated by a predicate
 pub struct Split<'data, T: 'data, P> {
     slice: &'data [T],
@@ -517,3 +1415,759 @@ impl<'data, 'sep, T, P> UnindexedProducer for SplitProducer<'data, 'sep, T, P>
         }
     }
 }
+
+
+/// Parallel iterator over mutable slices separated by a predicate
+pub struct SplitMut<'data, T: 'data, P> {
+    slice: &'data mut [T],
+    separator: P,
+}
+
+struct SplitMutProducer<'data, 'sep, T: 'data, P: 'sep> {
+    slice: &'data mut [T],
+    separator: &'sep P,
+
+    /// Marks the endpoint beyond which we've already found no separators.
+    tail: usize,
+}
+
+impl<'data, T, P> ParallelIterator for SplitMut<'data, T, P>
+    where P: Fn(&T) -> bool + Sync,
+          T: Send
+{
+    type Item = &'data mut [T];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        let tail = self.slice.len();
+        let producer = SplitMutProducer {
+            slice: self.slice,
+            separator: &self.separator,
+            tail: tail,
+        };
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+impl<'data, 'sep, T, P> UnindexedProducer for SplitMutProducer<'data, 'sep, T, P>
+    where P: Fn(&T) -> bool + Sync,
+          T: Send
+{
+    type Item = &'data mut [T];
+
+    fn split(self) -> (Self, Option<Self>) {
+        let SplitMutProducer { slice, separator, tail } = self;
+
+        // Look forward for the separator, and failing that look backward.
+        let mid = tail / 2;
+        let index = slice[mid..tail].iter().position(separator)
+            .map(|i| mid + i)
+            .or_else(|| slice[..mid].iter().rposition(separator));
+
+        if let Some(index) = index {
+            let (left, right) = slice.split_at_mut(index);
+            let left_tail = cmp::min(mid, index);
+
+            // If we scanned backwards to find the separator, everything in
+            // the right side is exhausted, with no separators left to find.
+            let right_tail = if index < mid { 0 } else { tail - index - 1 };
+
+            (SplitMutProducer {
+                 slice: left,
+                 separator: separator,
+                 tail: left_tail,
+             },
+             Some(SplitMutProducer {
+                      slice: &mut right[1..],
+                      separator: separator,
+                      tail: right_tail,
+                  }))
+
+        } else {
+            (SplitMutProducer {
+                 slice: slice,
+                 separator: separator,
+                 tail: 0,
+             },
+             None)
+        }
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+        where F: Folder<Self::Item>
+    {
+        let SplitMutProducer { slice, separator, tail } = self;
+
+        if tail == slice.len() {
+            // No tail section, so just let `slice::split_mut` handle it.
+            folder.consume_iter(slice.split_mut(separator))
+
+        } else if let Some(index) = slice[..tail].iter().rposition(separator) {
+            // We found the last separator to complete the tail, so
+            // end with that slice after `slice::split_mut` finds the rest.
+            let (left, right) = slice.split_at_mut(index);
+            let folder = folder.consume_iter(left.split_mut(separator));
+            if folder.full() {
+                folder
+            } else {
+                // skip the separator
+                folder.consume(&mut right[1..])
+            }
+
+        } else {
+            // We know there are no separators at all.  Return our whole slice.
+            folder.consume(slice)
+        }
+    }
+}
+
+
+/// Parallel iterator over slices separated by a predicate, in reverse order
+pub struct RSplit<'data, T: 'data, P> {
+    slice: &'data [T],
+    separator: P,
+}
+
+struct RSplitProducer<'data, 'sep, T: 'data, P: 'sep> {
+    slice: &'data [T],
+    separator: &'sep P,
+
+    /// Marks the endpoint beyond which we've already found no separators.
+    ///
+    /// This has the same meaning as `SplitProducer::tail`; the *meaning* of
+    /// a clean region doesn't depend on which direction we iterate in, only
+    /// which half of a `split` is returned as `self` (and thus processed
+    /// first) does.
+    tail: usize,
+}
+
+impl<'data, T, P> ParallelIterator for RSplit<'data, T, P>
+    where P: Fn(&T) -> bool + Sync,
+          T: Sync
+{
+    type Item = &'data [T];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        let producer = RSplitProducer {
+            slice: self.slice,
+            separator: &self.separator,
+            tail: self.slice.len(),
+        };
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+impl<'data, 'sep, T, P> UnindexedProducer for RSplitProducer<'data, 'sep, T, P>
+    where P: Fn(&T) -> bool + Sync,
+          T: Sync
+{
+    type Item = &'data [T];
+
+    fn split(self) -> (Self, Option<Self>) {
+        let RSplitProducer { slice, separator, tail } = self;
+
+        // Look forward for the separator, and failing that look backward.
+        // This is the same search `SplitProducer` performs; only the roles
+        // of the two resulting halves are swapped below, so that the half
+        // closer to the end of the slice is processed (and thus emitted)
+        // first.
+        let mid = tail / 2;
+        let index = slice[mid..tail].iter().position(separator)
+            .map(|i| mid + i)
+            .or_else(|| slice[..mid].iter().rposition(separator));
+
+        if let Some(index) = index {
+            let (left, right) = slice.split_at(index);
+            let left_tail = cmp::min(mid, index);
+            let right_tail = if index < mid { 0 } else { tail - index - 1 };
+
+            let left = RSplitProducer {
+                slice: left,
+                separator: separator,
+                tail: left_tail,
+            };
+            let right = RSplitProducer {
+                slice: &right[1..],
+                separator: separator,
+                tail: right_tail,
+            };
+
+            // Swap the order relative to `SplitProducer`, so the half
+            // nearer the end of the slice is processed first.
+            (right, Some(left))
+
+        } else {
+            (RSplitProducer {
+                 slice: slice,
+                 separator: separator,
+                 tail: 0,
+             },
+             None)
+        }
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+        where F: Folder<Self::Item>
+    {
+        let RSplitProducer { slice, separator, tail } = self;
+
+        if tail == slice.len() {
+            // No tail section, so just let `slice::rsplit` handle it.
+            folder.consume_iter(slice.rsplit(separator))
+
+        } else if let Some(index) = slice[..tail].iter().rposition(separator) {
+            // We found the last separator to complete the tail; in reverse
+            // order that clean tail section comes first, followed by
+            // whatever `slice::rsplit` finds in the rest.
+            let (left, right) = slice.split_at(index);
+            let folder = folder.consume(&right[1..]);
+            if folder.full() {
+                folder
+            } else {
+                folder.consume_iter(left.rsplit(separator))
+            }
+
+        } else {
+            // We know there are no separators at all.  Return our whole slice.
+            folder.consume(slice)
+        }
+    }
+}
+
+
+/// Parallel iterator over mutable slices separated by a predicate,
+/// in reverse order
+pub struct RSplitMut<'data, T: 'data, P> {
+    slice: &'data mut [T],
+    separator: P,
+}
+
+struct RSplitMutProducer<'data, 'sep, T: 'data, P: 'sep> {
+    slice: &'data mut [T],
+    separator: &'sep P,
+
+    /// Marks the endpoint beyond which we've already found no separators.
+    tail: usize,
+}
+
+impl<'data, T, P> ParallelIterator for RSplitMut<'data, T, P>
+    where P: Fn(&T) -> bool + Sync,
+          T: Send
+{
+    type Item = &'data mut [T];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        let tail = self.slice.len();
+        let producer = RSplitMutProducer {
+            slice: self.slice,
+            separator: &self.separator,
+            tail: tail,
+        };
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+impl<'data, 'sep, T, P> UnindexedProducer for RSplitMutProducer<'data, 'sep, T, P>
+    where P: Fn(&T) -> bool + Sync,
+          T: Send
+{
+    type Item = &'data mut [T];
+
+    fn split(self) -> (Self, Option<Self>) {
+        let RSplitMutProducer { slice, separator, tail } = self;
+
+        let mid = tail / 2;
+        let index = slice[mid..tail].iter().position(separator)
+            .map(|i| mid + i)
+            .or_else(|| slice[..mid].iter().rposition(separator));
+
+        if let Some(index) = index {
+            let (left, right) = slice.split_at_mut(index);
+            let left_tail = cmp::min(mid, index);
+            let right_tail = if index < mid { 0 } else { tail - index - 1 };
+
+            let left = RSplitMutProducer {
+                slice: left,
+                separator: separator,
+                tail: left_tail,
+            };
+            let right = RSplitMutProducer {
+                slice: &mut right[1..],
+                separator: separator,
+                tail: right_tail,
+            };
+
+            // Swap the order relative to `SplitMutProducer`, so the half
+            // nearer the end of the slice is processed first.
+            (right, Some(left))
+
+        } else {
+            (RSplitMutProducer {
+                 slice: slice,
+                 separator: separator,
+                 tail: 0,
+             },
+             None)
+        }
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+        where F: Folder<Self::Item>
+    {
+        let RSplitMutProducer { slice, separator, tail } = self;
+
+        if tail == slice.len() {
+            folder.consume_iter(slice.rsplit_mut(separator))
+
+        } else if let Some(index) = slice[..tail].iter().rposition(separator) {
+            let (left, right) = slice.split_at_mut(index);
+            let folder = folder.consume(&mut right[1..]);
+            if folder.full() {
+                folder
+            } else {
+                folder.consume_iter(left.rsplit_mut(separator))
+            }
+
+        } else {
+            folder.consume(slice)
+        }
+    }
+}
+
+
+/// Parallel iterator over slices separated by a predicate
+pub struct ChunkBy<'data, T: 'data, P> {
+    slice: &'data [T],
+    pred: P,
+}
+
+struct ChunkByProducer<'data, 'sep, T: 'data, P: 'sep> {
+    slice: &'data [T],
+    pred: &'sep P,
+
+    /// Marks the endpoint beyond which we've already found no group
+    /// boundaries, i.e. `slice[tail..]` is known to be a single run.
+    tail: usize,
+}
+
+impl<'data, T, P> ParallelIterator for ChunkBy<'data, T, P>
+    where P: Fn(&T, &T) -> bool + Sync,
+          T: Sync
+{
+    type Item = &'data [T];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        let producer = ChunkByProducer {
+            slice: self.slice,
+            pred: &self.pred,
+            tail: self.slice.len(),
+        };
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+/// Returns the index of the first group boundary (an index `i` for which
+/// `!pred(&slice[i - 1], &slice[i])`) at or after `mid`, or failing that the
+/// last one before `mid`. A boundary before `mid` is only returned when there
+/// is none at or after it, so the result is not always the nearest to `mid`.
+fn nearest_boundary<T, P>(slice: &[T], mid: usize, pred: &P) -> Option<usize>
+    where P: Fn(&T, &T) -> bool
+{
+    let is_boundary = |i: usize| !pred(&slice[i - 1], &slice[i]);
+    (mid..slice.len()).find(|&i| i >= 1 && is_boundary(i))
+        .or_else(|| (1..mid).rev().find(|&i| is_boundary(i)))
+}
+
+impl<'data, 'sep, T, P> UnindexedProducer for ChunkByProducer<'data, 'sep, T, P>
+    where P: Fn(&T, &T) -> bool + Sync,
+          T: Sync
+{
+    type Item = &'data [T];
+
+    fn split(self) -> (Self, Option<Self>) {
+        let ChunkByProducer { slice, pred, tail } = self;
+
+        // A slice of zero or one elements is always a single group, and
+        // can't be split any further without straddling it.
+        if slice.len() <= 1 {
+            return (ChunkByProducer {
+                        slice: slice,
+                        pred: pred,
+                        tail: slice.len(),
+                    },
+                    None);
+        }
+
+        let mid = cmp::max(1, cmp::min(tail / 2, slice.len() - 1));
+        match nearest_boundary(slice, mid, pred) {
+            Some(index) => {
+                let (left, right) = slice.split_at(index);
+
+                // If we had to scan backward from `mid` to find the
+                // boundary, then the forward half we scanned first is
+                // known to contain no further boundaries.
+                let left_tail = cmp::min(mid, index);
+                let right_tail = if index < mid { 0 } else { tail - index };
+
+                (ChunkByProducer {
+                     slice: left,
+                     pred: pred,
+                     tail: left_tail,
+                 },
+                 Some(ChunkByProducer {
+                          slice: right,
+                          pred: pred,
+                          tail: right_tail,
+                      }))
+            }
+            None => {
+                // The whole slice is a single group.
+                (ChunkByProducer {
+                     slice: slice,
+                     pred: pred,
+                     tail: 0,
+                 },
+                 None)
+            }
+        }
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+        where F: Folder<Self::Item>
+    {
+        let ChunkByProducer { slice, pred, tail } = self;
+
+        if tail == slice.len() {
+            // No tail section, so just let `slice::chunk_by` handle it.
+            folder.consume_iter(slice.chunk_by(|a, b| pred(a, b)))
+
+        } else if let Some(index) = (1..tail).rev().find(|&i| !pred(&slice[i - 1], &slice[i])) {
+            // We found the last boundary to complete the tail, so end with
+            // that slice after `slice::chunk_by` finds the rest.
+            let (left, right) = slice.split_at(index);
+            let folder = folder.consume_iter(left.chunk_by(|a, b| pred(a, b)));
+            if folder.full() {
+                folder
+            } else {
+                folder.consume(right)
+            }
+
+        } else {
+            // We know the whole slice is a single group.
+            folder.consume(slice)
+        }
+    }
+}
+
+
+/// Parallel iterator over mutable slices separated by a predicate
+pub struct ChunkByMut<'data, T: 'data, P> {
+    slice: &'data mut [T],
+    pred: P,
+}
+
+struct ChunkByMutProducer<'data, 'sep, T: 'data, P: 'sep> {
+    slice: &'data mut [T],
+    pred: &'sep P,
+
+    /// Marks the endpoint beyond which we've already found no group
+    /// boundaries, i.e. `slice[tail..]` is known to be a single run.
+    tail: usize,
+}
+
+impl<'data, T, P> ParallelIterator for ChunkByMut<'data, T, P>
+    where P: Fn(&T, &T) -> bool + Sync,
+          T: Send
+{
+    type Item = &'data mut [T];
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        let tail = self.slice.len();
+        let producer = ChunkByMutProducer {
+            slice: self.slice,
+            pred: &self.pred,
+            tail: tail,
+        };
+        bridge_unindexed(producer, consumer)
+    }
+}
+
+impl<'data, 'sep, T, P> UnindexedProducer for ChunkByMutProducer<'data, 'sep, T, P>
+    where P: Fn(&T, &T) -> bool + Sync,
+          T: Send
+{
+    type Item = &'data mut [T];
+
+    fn split(self) -> (Self, Option<Self>) {
+        let ChunkByMutProducer { slice, pred, tail } = self;
+
+        if slice.len() <= 1 {
+            let len = slice.len();
+            return (ChunkByMutProducer {
+                        slice: slice,
+                        pred: pred,
+                        tail: len,
+                    },
+                    None);
+        }
+
+        let mid = cmp::max(1, cmp::min(tail / 2, slice.len() - 1));
+        match nearest_boundary(slice, mid, pred) {
+            Some(index) => {
+                let (left, right) = slice.split_at_mut(index);
+                let left_tail = cmp::min(mid, index);
+                let right_tail = if index < mid { 0 } else { tail - index };
+
+                (ChunkByMutProducer {
+                     slice: left,
+                     pred: pred,
+                     tail: left_tail,
+                 },
+                 Some(ChunkByMutProducer {
+                          slice: right,
+                          pred: pred,
+                          tail: right_tail,
+                      }))
+            }
+            None => {
+                (ChunkByMutProducer {
+                     slice: slice,
+                     pred: pred,
+                     tail: 0,
+                 },
+                 None)
+            }
+        }
+    }
+
+    fn fold_with<F>(self, folder: F) -> F
+        where F: Folder<Self::Item>
+    {
+        let ChunkByMutProducer { slice, pred, tail } = self;
+
+        if tail == slice.len() {
+            folder.consume_iter(slice.chunk_by_mut(|a, b| pred(a, b)))
+
+        } else if let Some(index) = (1..tail).rev().find(|&i| !pred(&slice[i - 1], &slice[i])) {
+            let (left, right) = slice.split_at_mut(index);
+            let folder = folder.consume_iter(left.chunk_by_mut(|a, b| pred(a, b)));
+            if folder.full() {
+                folder
+            } else {
+                folder.consume(right)
+            }
+
+        } else {
+            folder.consume(slice)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn random_vec(seed: &mut u64, len: usize) -> Vec<i32> {
+        (0..len)
+            .map(|_| {
+                     *seed ^= *seed << 13;
+                     *seed ^= *seed >> 7;
+                     *seed ^= *seed << 17;
+                     (*seed % 20) as i32
+                 })
+            .collect()
+    }
+
+    #[test]
+    fn par_rchunks_matches_sequential() {
+        let mut seed = 0x1234_5678_9abc_def0u64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            let v = random_vec(&mut seed, len);
+            for chunk_size in 1..(len + 2) {
+                let expected: Vec<Vec<i32>> = v.rchunks(chunk_size).map(|c| c.to_vec()).collect();
+                let actual: Vec<Vec<i32>> =
+                    v.par_rchunks(chunk_size).map(|c| c.to_vec()).collect();
+                assert_eq!(actual, expected, "len={} chunk_size={}", len, chunk_size);
+            }
+        }
+    }
+
+    #[test]
+    fn par_rchunks_mut_matches_sequential() {
+        let mut seed = 0x0fed_cba9_8765_4321u64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            for chunk_size in 1..(len + 2) {
+                let base = random_vec(&mut seed, len);
+
+                let mut expected = base.clone();
+                for chunk in expected.rchunks_mut(chunk_size) {
+                    for x in chunk {
+                        *x *= 2;
+                    }
+                }
+
+                let mut actual = base.clone();
+                actual.par_rchunks_mut(chunk_size)
+                    .for_each(|chunk| for x in chunk {
+                                  *x *= 2;
+                              });
+
+                assert_eq!(actual, expected, "len={} chunk_size={}", len, chunk_size);
+            }
+        }
+    }
+
+    #[test]
+    fn par_chunks_exact_matches_sequential() {
+        let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            let v = random_vec(&mut seed, len);
+            for chunk_size in 1..(len + 2) {
+                let seq = v.chunks_exact(chunk_size);
+                let expected_remainder = seq.remainder().to_vec();
+                let expected: Vec<Vec<i32>> = seq.map(|c| c.to_vec()).collect();
+
+                let par = v.par_chunks_exact(chunk_size);
+                let actual_remainder = par.remainder().to_vec();
+                let actual: Vec<Vec<i32>> = par.map(|c| c.to_vec()).collect();
+
+                assert_eq!(actual, expected, "len={} chunk_size={}", len, chunk_size);
+                assert_eq!(actual_remainder, expected_remainder);
+            }
+        }
+    }
+
+    #[test]
+    fn par_chunks_exact_mut_matches_sequential() {
+        let mut seed = 0x4f6c_dd1d_2545_f491u64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            for chunk_size in 1..(len + 2) {
+                let base = random_vec(&mut seed, len);
+
+                let mut expected = base.clone();
+                {
+                    let mut seq = expected.chunks_exact_mut(chunk_size);
+                    for chunk in &mut seq {
+                        for x in chunk {
+                            *x *= 2;
+                        }
+                    }
+                }
+
+                let mut actual = base.clone();
+                {
+                    let par = actual.par_chunks_exact_mut(chunk_size);
+                    par.for_each(|chunk| for x in chunk {
+                                     *x *= 2;
+                                 });
+                }
+
+                assert_eq!(actual, expected, "len={} chunk_size={}", len, chunk_size);
+            }
+        }
+    }
+
+    #[test]
+    fn par_split_mut_matches_sequential() {
+        let mut seed = 0x1122_3344_5566_7788u64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            let base = random_vec(&mut seed, len);
+
+            let mut expected = base.clone();
+            let expected: Vec<Vec<i32>> = expected
+                .split_mut(|x| x % 3 == 0)
+                .map(|s| s.to_vec())
+                .collect();
+
+            let mut actual = base.clone();
+            let actual: Vec<Vec<i32>> = actual
+                .par_split_mut(|x| x % 3 == 0)
+                .map(|s| s.to_vec())
+                .collect();
+
+            assert_eq!(actual, expected, "len={}", len);
+        }
+    }
+
+    #[test]
+    fn par_rsplit_matches_sequential() {
+        let mut seed = 0x2233_4455_6677_8899u64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            let v = random_vec(&mut seed, len);
+
+            let expected: Vec<Vec<i32>> =
+                v.rsplit(|x| x % 3 == 0).map(|s| s.to_vec()).collect();
+            let actual: Vec<Vec<i32>> =
+                v.par_rsplit(|x| x % 3 == 0).map(|s| s.to_vec()).collect();
+
+            assert_eq!(actual, expected, "len={}", len);
+        }
+    }
+
+    #[test]
+    fn par_rsplit_mut_matches_sequential() {
+        let mut seed = 0x3344_5566_7788_99aau64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            let base = random_vec(&mut seed, len);
+
+            let mut expected = base.clone();
+            let expected: Vec<Vec<i32>> = expected
+                .rsplit_mut(|x| x % 3 == 0)
+                .map(|s| s.to_vec())
+                .collect();
+
+            let mut actual = base.clone();
+            let actual: Vec<Vec<i32>> = actual
+                .par_rsplit_mut(|x| x % 3 == 0)
+                .map(|s| s.to_vec())
+                .collect();
+
+            assert_eq!(actual, expected, "len={}", len);
+        }
+    }
+
+    #[test]
+    fn par_chunk_by_matches_sequential() {
+        let mut seed = 0x4455_6677_8899_aabbu64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            let v = random_vec(&mut seed, len);
+
+            let expected: Vec<Vec<i32>> = v.chunk_by(|a, b| a <= b).map(|s| s.to_vec()).collect();
+            let actual: Vec<Vec<i32>> =
+                v.par_chunk_by(|a, b| a <= b).map(|s| s.to_vec()).collect();
+
+            assert_eq!(actual, expected, "len={}", len);
+        }
+    }
+
+    #[test]
+    fn par_chunk_by_mut_matches_sequential() {
+        let mut seed = 0x5566_7788_99aa_bbccu64;
+        for &len in &[0usize, 1, 2, 5, 7, 10, 31, 100] {
+            let base = random_vec(&mut seed, len);
+
+            let mut expected = base.clone();
+            let expected: Vec<Vec<i32>> = expected
+                .chunk_by_mut(|a, b| a <= b)
+                .map(|s| s.to_vec())
+                .collect();
+
+            let mut actual = base.clone();
+            let actual: Vec<Vec<i32>> = actual
+                .par_chunk_by_mut(|a, b| a <= b)
+                .map(|s| s.to_vec())
+                .collect();
+
+            assert_eq!(actual, expected, "len={}", len);
+        }
+    }
+}